@@ -0,0 +1,84 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+
+use std::io::Write;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+use termcolor::Ansi;
+use termcolor::Color::Ansi256;
+use termcolor::ColorSpec;
+use termcolor::WriteColor;
+
+static USE_COLOR: AtomicBool = AtomicBool::new(false);
+
+pub fn enable_color() {
+  USE_COLOR.store(true, Ordering::SeqCst);
+}
+
+pub fn disable_color() {
+  USE_COLOR.store(false, Ordering::SeqCst);
+}
+
+fn use_color() -> bool {
+  USE_COLOR.load(Ordering::SeqCst)
+}
+
+fn style(s: &str, colorspec: ColorSpec) -> String {
+  if !use_color() {
+    return s.to_string();
+  }
+  let mut v = Vec::new();
+  let mut ansi_writer = Ansi::new(&mut v);
+  ansi_writer.set_color(&colorspec).unwrap();
+  ansi_writer.write_all(s.as_bytes()).unwrap();
+  ansi_writer.reset().unwrap();
+  String::from_utf8_lossy(&v).to_string()
+}
+
+pub fn red_bold(s: &str) -> String {
+  let mut style_spec = ColorSpec::new();
+  style_spec.set_fg(Some(Ansi256(9))).set_bold(true);
+  style(s, style_spec)
+}
+
+pub fn green_bold(s: &str) -> String {
+  let mut style_spec = ColorSpec::new();
+  style_spec.set_fg(Some(Ansi256(10))).set_bold(true);
+  style(s, style_spec)
+}
+
+pub fn italic_bold(s: &str) -> String {
+  let mut style_spec = ColorSpec::new();
+  style_spec.set_bold(true).set_italic(true);
+  style(s, style_spec)
+}
+
+pub fn gray(s: &str) -> String {
+  let mut style_spec = ColorSpec::new();
+  style_spec.set_fg(Some(Ansi256(8)));
+  style(s, style_spec)
+}
+
+pub fn italic_gray(s: &str) -> String {
+  let mut style_spec = ColorSpec::new();
+  style_spec.set_fg(Some(Ansi256(8))).set_italic(true);
+  style(s, style_spec)
+}
+
+pub fn bold(s: &str) -> String {
+  let mut style_spec = ColorSpec::new();
+  style_spec.set_bold(true);
+  style(s, style_spec)
+}
+
+pub fn magenta(s: &str) -> String {
+  let mut style_spec = ColorSpec::new();
+  style_spec.set_fg(Some(Ansi256(13)));
+  style(s, style_spec)
+}
+
+pub fn cyan(s: &str) -> String {
+  let mut style_spec = ColorSpec::new();
+  style_spec.set_fg(Some(Ansi256(14)));
+  style(s, style_spec)
+}
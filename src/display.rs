@@ -0,0 +1,82 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+
+use std::fmt;
+
+pub struct Indent(pub i64);
+
+impl fmt::Display for Indent {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for _ in 0..self.0 {
+      write!(f, "  ")?;
+    }
+    Ok(())
+  }
+}
+
+pub struct SliceDisplayer<'a, T: fmt::Display>(&'a [T], &'a str, bool);
+
+impl<'a, T: fmt::Display> SliceDisplayer<'a, T> {
+  pub fn new(
+    slice: &'a [T],
+    separator: &'a str,
+    trailing_separator: bool,
+  ) -> Self {
+    Self(slice, separator, trailing_separator)
+  }
+}
+
+impl<'a, T: fmt::Display> fmt::Display for SliceDisplayer<'a, T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut iter = self.0.iter();
+    if let Some(first) = iter.next() {
+      write!(f, "{}", first)?;
+      for element in iter {
+        write!(f, "{}{}", self.1, element)?;
+      }
+      if self.2 {
+        write!(f, "{}", self.1)?;
+      }
+    }
+    Ok(())
+  }
+}
+
+pub fn display_abstract(is_abstract: bool) -> &'static str {
+  if is_abstract {
+    "abstract "
+  } else {
+    ""
+  }
+}
+
+pub fn display_async(is_async: bool) -> &'static str {
+  if is_async {
+    "async "
+  } else {
+    ""
+  }
+}
+
+pub fn display_generator(is_generator: bool) -> &'static str {
+  if is_generator {
+    "*"
+  } else {
+    ""
+  }
+}
+
+pub fn display_readonly(is_readonly: bool) -> &'static str {
+  if is_readonly {
+    "readonly "
+  } else {
+    ""
+  }
+}
+
+pub fn display_optional(is_optional: bool) -> &'static str {
+  if is_optional {
+    "?"
+  } else {
+    ""
+  }
+}
@@ -0,0 +1,36 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+
+//! The shared rendering contract implemented by the single-document output
+//! backends (`DocPrinter` for the terminal, `MarkdownPrinter` for
+//! GitHub-flavored Markdown). Keeping signature/jsdoc/member rendering
+//! behind this trait means those backends stay in sync as the `DocNode`
+//! model evolves, instead of each re-deriving its own copy of the same
+//! logic.
+//!
+//! `HtmlRenderer` does not implement this trait: it renders one page per
+//! `DocNode` and needs a breadcrumb-qualified `PageContext` threaded through
+//! signature/member rendering to link to the right page, which this
+//! trait's flat, context-free methods have no room for.
+
+use crate::js_doc::JsDoc;
+use crate::node::DocNode;
+use crate::node::ParamDef;
+
+pub trait DocFormatter {
+  /// Renders a node's signature (e.g. `class Foo extends Bar`) with no
+  /// trailing members.
+  fn format_signature(&self, node: &DocNode) -> String;
+
+  /// Renders a node's jsdoc: the markdown body plus recognized block tags,
+  /// matching `@param`/`@returns` against the given signature pieces.
+  fn format_jsdoc(
+    &self,
+    js_doc: &JsDoc,
+    params: Option<&[ParamDef]>,
+    has_return_type: bool,
+  ) -> String;
+
+  /// Renders a class/enum/interface/namespace's members. Empty for kinds
+  /// that have none.
+  fn format_members(&self, node: &DocNode) -> String;
+}
@@ -0,0 +1,789 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+
+//! A static HTML rendering backend, sibling to the terminal `DocPrinter`.
+//!
+//! Unlike `DocPrinter`, which writes a single stream of ANSI-colored text,
+//! `HtmlRenderer` produces a small set of linked pages: one `index.html`
+//! listing every top-level item grouped by `kind_order`, and one page per
+//! `DocNode` with a breadcrumb, a signature block, and rendered members.
+//!
+//! Its per-node rendering methods take a `PageContext` so cross-links and
+//! namespace-member links resolve to the right page from wherever in the
+//! breadcrumb they're rendered. That's one parameter more than
+//! `DocFormatter` has room for, so `HtmlRenderer` intentionally does not
+//! implement it — `DocPrinter` and `MarkdownPrinter` share that trait
+//! instead, since both render to a single flat document with no
+//! per-page context to carry.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+use crate::js_doc::split_links;
+use crate::js_doc::DocSegment;
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::node::kind_order;
+use crate::node::ClassDef;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+use crate::node::EnumDef;
+use crate::node::InterfaceDef;
+use crate::node::NamespaceDef;
+use crate::node::ParamDef;
+use crate::printer::Detail;
+
+/// Right-hand sides longer than this are rendered as a collapsed, expandable
+/// `type-decl`, matching `DocPrinter`'s summary-mode elision.
+const SUMMARY_TYPE_ALIAS_MAX_LEN: usize = 60;
+
+/// A read-only index from item name to the page(s) that document it. Built
+/// by a single crawl of the node tree so that cross-links can be resolved
+/// without re-walking the tree for every item rendered.
+///
+/// A bare name can be declared in more than one namespace (two `shared`
+/// functions in namespaces `A` and `B`), so each name maps to every
+/// namespace it was declared in, paired with the page that documents it.
+/// `resolve` disambiguates using the referencing page's own scope.
+#[derive(Debug, Default)]
+struct NameCache(HashMap<String, Vec<(String, String)>>);
+
+impl NameCache {
+  fn build(doc_nodes: &[DocNode]) -> Self {
+    let mut cache = HashMap::new();
+    Self::crawl(doc_nodes, "", &mut cache);
+    Self(cache)
+  }
+
+  /// `parent_path` is the dot-joined, breadcrumb-qualified path of the
+  /// enclosing namespace (empty at the root), so that pages are keyed by
+  /// the same qualified path used everywhere else — two nodes with the same
+  /// bare name in different namespaces still land on distinct pages.
+  fn crawl(
+    doc_nodes: &[DocNode],
+    parent_path: &str,
+    cache: &mut HashMap<String, Vec<(String, String)>>,
+  ) {
+    for node in doc_nodes {
+      if node.kind == DocNodeKind::Import {
+        continue;
+      }
+      let path = qualify(parent_path, &node.name);
+      cache
+        .entry(node.name.clone())
+        .or_default()
+        .push((parent_path.to_string(), page_file_name(&path)));
+      if let Some(namespace_def) = &node.namespace_def {
+        Self::crawl(&namespace_def.elements, &path, cache);
+      }
+    }
+  }
+
+  /// Resolve a type name to a relative link, if it refers to a documented
+  /// page. `scope` is the qualified path of the namespace the reference
+  /// itself appears in; declarations in that namespace (or, failing that,
+  /// its nearest enclosing ancestor) win over a same-named declaration
+  /// elsewhere in the tree — the same lexical-scoping convention most
+  /// languages use for unqualified name lookup. Names that don't resolve
+  /// anywhere (globals, built-ins, unexported types) are left as plain text
+  /// by the caller.
+  fn resolve(&self, name: &str, scope: &str) -> Option<&str> {
+    let candidates = self.0.get(name)?;
+    let mut ancestor = Some(scope);
+    while let Some(path) = ancestor {
+      if let Some((_, page)) = candidates.iter().find(|(decl_scope, _)| decl_scope == path) {
+        return Some(page.as_str());
+      }
+      ancestor = if path.is_empty() {
+        None
+      } else {
+        Some(path.rsplit_once('.').map_or("", |(rest, _)| rest))
+      };
+    }
+    candidates.first().map(|(_, page)| page.as_str())
+  }
+}
+
+fn page_file_name(qualified_path: &str) -> String {
+  format!("{}.html", qualified_path.replace('.', "_"))
+}
+
+/// Joins a parent's dot-separated qualified path with a child name.
+fn qualify(parent_path: &str, name: &str) -> String {
+  if parent_path.is_empty() {
+    name.to_string()
+  } else {
+    format!("{}.{}", parent_path, name)
+  }
+}
+
+fn escape(s: &str) -> String {
+  s.replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+}
+
+/// Per-page rendering context: the breadcrumb path down to the node being
+/// rendered, and a shared reference to the cross-link cache.
+struct PageContext<'a> {
+  cache: &'a NameCache,
+  breadcrumb: Vec<String>,
+}
+
+impl<'a> PageContext<'a> {
+  fn child(&self, name: &str) -> PageContext<'a> {
+    let mut breadcrumb = self.breadcrumb.clone();
+    breadcrumb.push(name.to_string());
+    PageContext {
+      cache: self.cache,
+      breadcrumb,
+    }
+  }
+
+  /// The dot-joined, breadcrumb-qualified path to this page — the same
+  /// thing used to key pages in `render_pages` and to build the breadcrumb
+  /// nav, so every link to this page agrees on its file name.
+  fn path(&self) -> String {
+    self.breadcrumb.join(".")
+  }
+
+  /// The qualified path of the namespace this page's node is itself
+  /// declared in — one level up from `path()`. Used to scope cross-link
+  /// resolution: a reference written on this page should resolve against
+  /// names visible in the namespace the page's node lives in.
+  fn parent_path(&self) -> String {
+    self.breadcrumb[..self.breadcrumb.len().saturating_sub(1)].join(".")
+  }
+}
+
+pub struct HtmlRenderer<'a> {
+  doc_nodes: &'a [DocNode],
+  private: bool,
+  detail: Detail,
+  cache: NameCache,
+}
+
+impl<'a> HtmlRenderer<'a> {
+  pub fn new(doc_nodes: &'a [DocNode], private: bool, detail: Detail) -> Self {
+    let cache = NameCache::build(doc_nodes);
+    HtmlRenderer {
+      doc_nodes,
+      private,
+      detail,
+      cache,
+    }
+  }
+
+  /// Wraps `body` in a `<details>` element so it's collapsed behind an
+  /// expander in `Detail::Summary` mode, and renders it open in `Detail::Full`.
+  fn collapsible(&self, summary: &str, body: &str) -> String {
+    match self.detail {
+      Detail::Full => body.to_string(),
+      Detail::Summary => format!(
+        "<details><summary>{}</summary>\n{}</details>\n",
+        summary, body
+      ),
+    }
+  }
+
+  /// Render every page. Returns a map of file name to HTML contents; callers
+  /// are responsible for writing these out to disk.
+  pub fn render(&self) -> HashMap<String, String> {
+    let mut pages = HashMap::new();
+    pages.insert("index.html".to_string(), self.render_index());
+
+    let ctx = PageContext {
+      cache: &self.cache,
+      breadcrumb: vec![],
+    };
+    self.render_pages(self.doc_nodes, &ctx, &mut pages);
+    pages
+  }
+
+  fn render_pages(
+    &self,
+    doc_nodes: &[DocNode],
+    ctx: &PageContext,
+    pages: &mut HashMap<String, String>,
+  ) {
+    for node in doc_nodes {
+      if node.kind == DocNodeKind::Import {
+        continue;
+      }
+      let node_ctx = ctx.child(&node.name);
+      pages.insert(
+        page_file_name(&node_ctx.path()),
+        self.render_page(node, &node_ctx),
+      );
+      if let Some(namespace_def) = &node.namespace_def {
+        self.render_pages(&namespace_def.elements, &node_ctx, pages);
+      }
+    }
+  }
+
+  fn sorted<'b>(&self, doc_nodes: &'b [DocNode]) -> Vec<&'b DocNode> {
+    let mut sorted = doc_nodes.iter().collect::<Vec<_>>();
+    sorted.sort_unstable_by(|a, b| {
+      let kind_cmp = kind_order(&a.kind).cmp(&kind_order(&b.kind));
+      if kind_cmp == core::cmp::Ordering::Equal {
+        a.name.cmp(&b.name)
+      } else {
+        kind_cmp
+      }
+    });
+    sorted
+  }
+
+  fn render_index(&self) -> String {
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html><head><title>Index</title></head><body>\n");
+    out.push_str("<h1>Index</h1>\n<ul class=\"index\">\n");
+    for node in self.sorted(self.doc_nodes) {
+      if node.kind == DocNodeKind::Import {
+        continue;
+      }
+      let _ = writeln!(
+        out,
+        "<li><a href=\"{}\">{}</a> <span class=\"kind\">{:?}</span></li>",
+        page_file_name(&node.name),
+        escape(&node.name),
+        node.kind
+      );
+    }
+    out.push_str("</ul>\n</body></html>\n");
+    out
+  }
+
+  fn render_breadcrumb(&self, ctx: &PageContext) -> String {
+    let mut out = String::from("<nav class=\"breadcrumb\"><a href=\"index.html\">index</a>");
+    let mut path = String::new();
+    for segment in &ctx.breadcrumb {
+      if !path.is_empty() {
+        path.push('.');
+      }
+      path.push_str(segment);
+      let _ = write!(
+        out,
+        " &rsaquo; <a href=\"{}\">{}</a>",
+        page_file_name(&path),
+        escape(segment)
+      );
+    }
+    out.push_str("</nav>\n");
+    out
+  }
+
+  fn render_page(&self, node: &DocNode, ctx: &PageContext) -> String {
+    let mut out = String::new();
+    let _ = write!(
+      out,
+      "<!DOCTYPE html>\n<html><head><title>{}</title></head><body>\n",
+      escape(&node.name)
+    );
+    out.push_str(&self.render_breadcrumb(ctx));
+    let _ = writeln!(out, "<h1>{}</h1>", escape(&node.name));
+
+    out.push_str("<div class=\"docblock type-decl\">\n");
+    out.push_str(&self.render_signature(node, ctx));
+    out.push_str("</div>\n");
+
+    out.push_str(&self.render_jsdoc(
+      &node.js_doc,
+      node.function_def.as_ref().map(|f| f.params.as_slice()),
+      node
+        .function_def
+        .as_ref()
+        .map(|f| f.return_type.is_some())
+        .unwrap_or(false),
+      ctx,
+    ));
+
+    match node.kind {
+      DocNodeKind::Class => {
+        out.push_str(&self.collapsible("Members", &self.render_class(node, ctx)))
+      }
+      DocNodeKind::Enum => {
+        out.push_str(&self.collapsible("Members", &self.render_enum(node)))
+      }
+      DocNodeKind::Interface => {
+        out.push_str(&self.collapsible("Members", &self.render_interface(node, ctx)))
+      }
+      DocNodeKind::Namespace => {
+        out.push_str(&self.collapsible("Members", &self.render_namespace(node, ctx)))
+      }
+      _ => {}
+    }
+
+    out.push_str("</body></html>\n");
+    out
+  }
+
+  /// Renders a node's jsdoc: the markdown body plus recognized block tags,
+  /// matching `@param`/`@returns` against the given signature pieces. Mirrors
+  /// `MarkdownPrinter::format_jsdoc`, but resolves `{@link}` references to
+  /// pages instead of anchors.
+  fn render_jsdoc(
+    &self,
+    js_doc: &JsDoc,
+    params: Option<&[ParamDef]>,
+    has_return_type: bool,
+    ctx: &PageContext,
+  ) -> String {
+    let mut out = String::new();
+    if let Some(doc) = &js_doc.doc {
+      for line in doc.lines() {
+        let _ = writeln!(out, "<p class=\"jsdoc\">{}</p>", self.render_line(line, ctx));
+      }
+    }
+
+    for tag in &js_doc.tags {
+      match tag {
+        JsDocTag::Deprecated { doc } => {
+          let _ = write!(out, "<p class=\"deprecated\"><strong>Deprecated</strong>");
+          if let Some(doc) = doc {
+            let _ = write!(out, ": {}", self.render_line(doc, ctx));
+          }
+          out.push_str("</p>\n");
+        }
+        JsDocTag::Example { doc } => {
+          let _ = writeln!(out, "<p><strong>Example:</strong></p>\n<pre><code>{}</code></pre>", escape(doc));
+        }
+        JsDocTag::See { doc } => {
+          let _ = writeln!(out, "<p><strong>See:</strong> {}</p>", self.render_line(doc, ctx));
+        }
+        JsDocTag::Param { .. } => {
+          // rendered below, associated with the signature's param list
+        }
+        JsDocTag::Return { doc } => {
+          if has_return_type {
+            let _ = write!(out, "<p><strong>Returns:</strong>");
+            if let Some(doc) = doc {
+              let _ = write!(out, " {}", self.render_line(doc, ctx));
+            }
+            out.push_str("</p>\n");
+          }
+        }
+        JsDocTag::Template { .. } | JsDocTag::Unsupported { .. } => {}
+      }
+    }
+
+    if let Some(params) = params {
+      out.push_str(&self.render_params(params, js_doc, ctx));
+    }
+
+    out
+  }
+
+  /// Splits `line` on `{@link}`/`{@linkcode}` references, resolving each to
+  /// a cross-link the same way `render_signature`'s type names are.
+  fn render_line(&self, line: &str, ctx: &PageContext) -> String {
+    let scope = ctx.parent_path();
+    split_links(line)
+      .into_iter()
+      .map(|segment| match segment {
+        DocSegment::Text(text) => escape(text),
+        DocSegment::Link(name) => self.link(name, &scope),
+      })
+      .collect()
+  }
+
+  /// A list of parameters, cross-referencing `@param` docs by name. Mirrors
+  /// `MarkdownPrinter::param_table`.
+  fn render_params(&self, params: &[ParamDef], js_doc: &JsDoc, ctx: &PageContext) -> String {
+    if params.is_empty() {
+      return String::new();
+    }
+    let mut out = String::from("<ul class=\"params\">\n");
+    for param in params {
+      let doc = js_doc.tags.iter().find_map(|tag| match tag {
+        JsDocTag::Param { name, doc } if name == &param.name => {
+          Some(doc.clone().unwrap_or_default())
+        }
+        _ => None,
+      });
+      let _ = write!(out, "<li><code>{}</code>", escape(&param.name));
+      if let Some(doc) = doc.filter(|d| !d.is_empty()) {
+        let _ = write!(out, " &mdash; {}", self.render_line(&doc, ctx));
+      }
+      out.push_str("</li>\n");
+    }
+    out.push_str("</ul>\n");
+    out
+  }
+
+  /// Reuses the same shape as `DocPrinter::format_*_signature`, but emits
+  /// an HTML fragment with cross-links instead of ANSI-colored text.
+  fn render_signature(&self, node: &DocNode, ctx: &PageContext) -> String {
+    let scope = ctx.parent_path();
+    let mut out = String::new();
+    match node.kind {
+      DocNodeKind::Class => {
+        let class_def = node.class_def.as_ref().unwrap();
+        let _ = write!(
+          out,
+          "<span class=\"kw\">{}class</span> <span class=\"name\">{}</span>",
+          if class_def.is_abstract { "abstract " } else { "" },
+          escape(&node.name)
+        );
+        if let Some(extends) = &class_def.extends {
+          let _ = write!(out, " extends {}", self.link(extends, &scope));
+        }
+        if !class_def.implements.is_empty() {
+          let links = class_def
+            .implements
+            .iter()
+            .map(|i| self.link(i, &scope))
+            .collect::<Vec<_>>()
+            .join(", ");
+          let _ = write!(out, " implements {}", links);
+        }
+      }
+      DocNodeKind::Interface => {
+        let interface_def = node.interface_def.as_ref().unwrap();
+        let _ = write!(
+          out,
+          "<span class=\"kw\">interface</span> <span class=\"name\">{}</span>",
+          escape(&node.name)
+        );
+        if !interface_def.extends.is_empty() {
+          let links = interface_def
+            .extends
+            .iter()
+            .map(|i| self.link(i, &scope))
+            .collect::<Vec<_>>()
+            .join(", ");
+          let _ = write!(out, " extends {}", links);
+        }
+      }
+      DocNodeKind::Enum => {
+        let _ = write!(
+          out,
+          "<span class=\"kw\">enum</span> <span class=\"name\">{}</span>",
+          escape(&node.name)
+        );
+      }
+      DocNodeKind::Namespace => {
+        let _ = write!(
+          out,
+          "<span class=\"kw\">namespace</span> <span class=\"name\">{}</span>",
+          escape(&node.name)
+        );
+      }
+      DocNodeKind::Function => {
+        let function_def = node.function_def.as_ref().unwrap();
+        let params = function_def
+          .params
+          .iter()
+          .map(|p| p.name.clone())
+          .collect::<Vec<_>>()
+          .join(", ");
+        let _ = write!(
+          out,
+          "<span class=\"kw\">function</span> <span class=\"name\">{}</span>({})",
+          escape(&node.name),
+          escape(&params)
+        );
+        if let Some(return_type) = &function_def.return_type {
+          let _ = write!(out, ": {}", self.link(&return_type.repr, &scope));
+        }
+      }
+      DocNodeKind::TypeAlias => {
+        let type_alias_def = node.type_alias_def.as_ref().unwrap();
+        let raw_len = type_alias_def.ts_type.repr.len();
+        let rhs = escape(&type_alias_def.ts_type.repr);
+        let _ = write!(
+          out,
+          "<span class=\"kw\">type</span> <span class=\"name\">{}</span> = {}",
+          escape(&node.name),
+          if self.detail == Detail::Summary && raw_len > SUMMARY_TYPE_ALIAS_MAX_LEN {
+            self.collapsible("...", &rhs)
+          } else {
+            rhs
+          }
+        );
+      }
+      DocNodeKind::Variable => {
+        let variable_def = node.variable_def.as_ref().unwrap();
+        let _ = write!(out, "<span class=\"name\">{}</span>", escape(&node.name));
+        if let Some(ts_type) = &variable_def.ts_type {
+          let _ = write!(out, ": {}", self.link(&ts_type.repr, &scope));
+        }
+      }
+      DocNodeKind::ModuleDoc | DocNodeKind::Import => {}
+    }
+    out
+  }
+
+  /// Emits `name` as plain escaped text, or as a link to its page if it
+  /// resolves to another documented `DocNode`. `scope` is the qualified
+  /// path of the namespace `name` is referenced from, used to disambiguate
+  /// same-named declarations in different namespaces (see `NameCache`).
+  fn link(&self, name: &str, scope: &str) -> String {
+    match self.cache.resolve(name, scope) {
+      Some(href) => format!(
+        "<a href=\"{}\">{}</a>",
+        href,
+        escape(name)
+      ),
+      None => escape(name),
+    }
+  }
+
+  fn render_class(&self, node: &DocNode, ctx: &PageContext) -> String {
+    let scope = ctx.parent_path();
+    let class_def: &ClassDef = node.class_def.as_ref().unwrap();
+    let mut out = String::from("<h2>Members</h2>\n<ul class=\"members\">\n");
+    for ctor in &class_def.constructors {
+      let _ = writeln!(out, "<li>{}</li>", escape(&ctor.to_string()));
+    }
+    for prop in class_def.properties.iter().filter(|p| {
+      self.private
+        || p.accessibility.unwrap_or(deno_ast::swc::ast::Accessibility::Public)
+          != deno_ast::swc::ast::Accessibility::Private
+    }) {
+      let mut entry = format!("<li>{}", escape(&prop.name));
+      if let Some(ts_type) = &prop.ts_type {
+        let _ = write!(entry, ": {}", self.link(&ts_type.repr, &scope));
+      }
+      entry.push_str("</li>");
+      out.push_str(&entry);
+      out.push('\n');
+    }
+    for method in class_def.methods.iter().filter(|m| {
+      self.private
+        || m.accessibility.unwrap_or(deno_ast::swc::ast::Accessibility::Public)
+          != deno_ast::swc::ast::Accessibility::Private
+    }) {
+      let _ = writeln!(out, "<li>{}</li>", escape(&method.to_string()));
+    }
+    out.push_str("</ul>\n");
+    out
+  }
+
+  fn render_enum(&self, node: &DocNode) -> String {
+    let enum_def: &EnumDef = node.enum_def.as_ref().unwrap();
+    let mut out = String::from("<h2>Members</h2>\n<ul class=\"members\">\n");
+    for member in &enum_def.members {
+      let _ = writeln!(out, "<li>{}</li>", escape(&member.name));
+    }
+    out.push_str("</ul>\n");
+    out
+  }
+
+  fn render_interface(&self, node: &DocNode, ctx: &PageContext) -> String {
+    let scope = ctx.parent_path();
+    let interface_def: &InterfaceDef = node.interface_def.as_ref().unwrap();
+    let mut out = String::from("<h2>Members</h2>\n<ul class=\"members\">\n");
+    for property_def in &interface_def.properties {
+      let mut entry = format!("<li>{}", escape(&property_def.name));
+      if let Some(ts_type) = &property_def.ts_type {
+        let _ = write!(entry, ": {}", self.link(&ts_type.repr, &scope));
+      }
+      entry.push_str("</li>");
+      out.push_str(&entry);
+      out.push('\n');
+    }
+    for method_def in &interface_def.methods {
+      let _ = writeln!(out, "<li>{}</li>", escape(&method_def.to_string()));
+    }
+    out.push_str("</ul>\n");
+    out
+  }
+
+  fn render_namespace(&self, node: &DocNode, ctx: &PageContext) -> String {
+    let namespace_def: &NamespaceDef = node.namespace_def.as_ref().unwrap();
+    let mut out = String::from("<h2>Members</h2>\n<ul class=\"members\">\n");
+    for element in self.sorted(&namespace_def.elements) {
+      let _ = writeln!(
+        out,
+        "<li><a href=\"{}\">{}</a></li>",
+        page_file_name(&qualify(&ctx.path(), &element.name)),
+        escape(&element.name)
+      );
+    }
+    out.push_str("</ul>\n");
+    out
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::node::ClassDef;
+  use crate::node::ClassPropertyDef;
+  use crate::node::EnumMemberDef;
+  use crate::node::FunctionDef;
+  use crate::node::InterfaceDef;
+  use crate::node::InterfacePropertyDef;
+  use crate::node::Location;
+
+  fn node(kind: DocNodeKind, name: &str) -> DocNode {
+    DocNode {
+      kind,
+      name: name.to_string(),
+      location: Location {
+        filename: "file.ts".to_string(),
+        line: 1,
+        col: 0,
+      },
+      js_doc: JsDoc::default(),
+      function_def: if kind == DocNodeKind::Function {
+        Some(FunctionDef {
+          params: vec![],
+          return_type: None,
+          is_async: false,
+          is_generator: false,
+          type_params: vec![],
+          decorators: vec![],
+        })
+      } else {
+        None
+      },
+      variable_def: None,
+      enum_def: None,
+      class_def: None,
+      type_alias_def: None,
+      namespace_def: None,
+      interface_def: None,
+      accessibility: None,
+    }
+  }
+
+  #[test]
+  fn render_keys_pages_by_qualified_path_not_bare_name() {
+    let mut a = node(DocNodeKind::Namespace, "A");
+    a.namespace_def = Some(NamespaceDef {
+      elements: vec![node(DocNodeKind::Function, "shared")],
+    });
+    let mut b = node(DocNodeKind::Namespace, "B");
+    b.namespace_def = Some(NamespaceDef {
+      elements: vec![node(DocNodeKind::Function, "shared")],
+    });
+
+    let nodes = vec![a, b];
+    let renderer = HtmlRenderer::new(&nodes, true, Detail::Full);
+    let pages = renderer.render();
+
+    assert!(pages.contains_key("A_shared.html"));
+    assert!(pages.contains_key("B_shared.html"));
+  }
+
+  #[test]
+  fn render_collapses_members_for_all_four_container_kinds_in_summary_mode() {
+    let mut class = node(DocNodeKind::Class, "AClass");
+    class.class_def = Some(ClassDef {
+      is_abstract: false,
+      extends: None,
+      implements: vec![],
+      type_params: vec![],
+      super_type_params: vec![],
+      decorators: vec![],
+      constructors: vec![],
+      properties: vec![ClassPropertyDef {
+        name: "prop".to_string(),
+        js_doc: JsDoc::default(),
+        ts_type: None,
+        accessibility: None,
+        decorators: vec![],
+      }],
+      methods: vec![],
+      index_signatures: vec![],
+    });
+
+    let mut enum_node = node(DocNodeKind::Enum, "AnEnum");
+    enum_node.enum_def = Some(EnumDef {
+      members: vec![EnumMemberDef {
+        name: "A".to_string(),
+        js_doc: JsDoc::default(),
+      }],
+    });
+
+    let mut interface = node(DocNodeKind::Interface, "AnInterface");
+    interface.interface_def = Some(InterfaceDef {
+      extends: vec![],
+      type_params: vec![],
+      properties: vec![InterfacePropertyDef {
+        name: "prop".to_string(),
+        js_doc: JsDoc::default(),
+        ts_type: None,
+      }],
+      methods: vec![],
+      index_signatures: vec![],
+    });
+
+    let mut namespace_member = node(DocNodeKind::Function, "member");
+    namespace_member.js_doc = JsDoc::default();
+    let mut namespace = node(DocNodeKind::Namespace, "ANamespace");
+    namespace.namespace_def = Some(NamespaceDef {
+      elements: vec![namespace_member],
+    });
+
+    let nodes = vec![class, enum_node, interface, namespace];
+    let renderer = HtmlRenderer::new(&nodes, true, Detail::Summary);
+    let pages = renderer.render();
+
+    for page in [
+      "AClass.html",
+      "AnEnum.html",
+      "AnInterface.html",
+      "ANamespace.html",
+    ] {
+      let html = &pages[page];
+      assert!(
+        html.contains("<details><summary>Members</summary>"),
+        "{} did not collapse its members: {}",
+        page,
+        html
+      );
+    }
+  }
+
+  #[test]
+  fn render_resolves_a_link_tag_to_the_referenced_page_and_renders_deprecated() {
+    let mut bar = node(DocNodeKind::Class, "Bar");
+    bar.class_def = Some(ClassDef {
+      is_abstract: false,
+      extends: None,
+      implements: vec![],
+      type_params: vec![],
+      super_type_params: vec![],
+      decorators: vec![],
+      constructors: vec![],
+      properties: vec![],
+      methods: vec![],
+      index_signatures: vec![],
+    });
+
+    let mut foo = node(DocNodeKind::Function, "foo");
+    foo.js_doc = JsDoc {
+      doc: Some("Does a thing. See {@link Bar} for details.".to_string()),
+      tags: vec![JsDocTag::Deprecated {
+        doc: Some("use Bar instead".to_string()),
+      }],
+    };
+    foo.function_def = Some(FunctionDef {
+      params: vec![],
+      return_type: None,
+      is_async: false,
+      is_generator: false,
+      type_params: vec![],
+      decorators: vec![],
+    });
+
+    let nodes = vec![foo, bar];
+    let renderer = HtmlRenderer::new(&nodes, true, Detail::Full);
+    let pages = renderer.render();
+    let html = &pages["foo.html"];
+
+    assert!(
+      html.contains("<a href=\"Bar.html\">Bar</a>"),
+      "{{@link Bar}} did not resolve to a cross-link: {}",
+      html
+    );
+    assert!(
+      html.contains("<p class=\"deprecated\"><strong>Deprecated</strong>: use Bar instead</p>"),
+      "deprecated tag was not rendered: {}",
+      html
+    );
+  }
+}
+
@@ -0,0 +1,231 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+
+//! A structured model for JSDoc comments.
+//!
+//! Historically `js_doc.doc` was printed line-by-line with no understanding
+//! of its contents. `JsDoc::parse` turns the raw comment text into a
+//! markdown body plus a list of recognized block tags, so printers can
+//! associate `@param`/`@returns` with the matching signature pieces,
+//! highlight `@deprecated`, render `@example` as code, and resolve
+//! `{@link Symbol}` references.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct JsDoc {
+  /// The markdown body, with any `{@link Symbol}` references left inline
+  /// as-is; printers resolve them at render time.
+  pub doc: Option<String>,
+  pub tags: Vec<JsDocTag>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum JsDocTag {
+  Param { name: String, doc: Option<String> },
+  Return { doc: Option<String> },
+  Example { doc: String },
+  Deprecated { doc: Option<String> },
+  See { doc: String },
+  Template { name: String },
+  /// A recognized-looking tag (`@foo ...`) this parser doesn't give special
+  /// treatment to; kept so it still round-trips and can be rendered as-is.
+  Unsupported { value: String },
+}
+
+/// Matches `{@link Symbol}` (and the `{@linkcode Symbol}` variant) anywhere
+/// in a doc body. A malformed or empty occurrence (`{@link}`, or one whose
+/// `}` actually belongs to a later tag) is skipped in place rather than
+/// aborting the whole scan, so later, well-formed links are still found.
+fn find_link(s: &str) -> Option<(usize, usize, &str)> {
+  const MARKER: &str = "{@link";
+  let mut offset = 0;
+  loop {
+    let start = offset + s[offset..].find(MARKER)?;
+    let end = start + s[start..].find('}')?;
+
+    let mut body = &s[start + MARKER.len()..end];
+    body = body.strip_prefix("code").unwrap_or(body);
+    let name = body.trim();
+
+    if name.is_empty() || name.contains('{') {
+      offset = start + MARKER.len();
+      continue;
+    }
+    return Some((start, end + 1, name));
+  }
+}
+
+/// Splits a doc body on `{@link Symbol}` references, returning the plain
+/// text segments interleaved with the linked symbol names.
+pub fn split_links(s: &str) -> Vec<DocSegment<'_>> {
+  let mut segments = Vec::new();
+  let mut rest = s;
+  while let Some((start, end, name)) = find_link(rest) {
+    if start > 0 {
+      segments.push(DocSegment::Text(&rest[..start]));
+    }
+    segments.push(DocSegment::Link(name));
+    rest = &rest[end..];
+  }
+  if !rest.is_empty() {
+    segments.push(DocSegment::Text(rest));
+  }
+  segments
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DocSegment<'a> {
+  Text(&'a str),
+  Link(&'a str),
+}
+
+impl JsDoc {
+  /// Parses a raw JSDoc comment body (already stripped of `/**`, `*/` and
+  /// leading `*`s) into a markdown body plus recognized block tags.
+  pub fn parse(input: &str) -> JsDoc {
+    let mut body_lines = Vec::new();
+    let mut tags = Vec::new();
+
+    let mut lines = input.lines().peekable();
+    while let Some(line) = lines.peek() {
+      if line.trim_start().starts_with('@') {
+        break;
+      }
+      body_lines.push(lines.next().unwrap());
+    }
+
+    while let Some(line) = lines.next() {
+      let trimmed = line.trim_start();
+      if !trimmed.starts_with('@') {
+        continue;
+      }
+      let mut tag_lines = vec![trimmed];
+      while let Some(next) = lines.peek() {
+        if next.trim_start().starts_with('@') {
+          break;
+        }
+        tag_lines.push(lines.next().unwrap());
+      }
+      tags.push(parse_tag(&tag_lines.join("\n")));
+    }
+
+    let doc = body_lines.join("\n");
+    JsDoc {
+      doc: if doc.trim().is_empty() { None } else { Some(doc) },
+      tags,
+    }
+  }
+}
+
+fn parse_tag(block: &str) -> JsDocTag {
+  let block = block.strip_prefix('@').unwrap_or(block);
+  let (tag, rest) = match block.find(char::is_whitespace) {
+    Some(idx) => (&block[..idx], block[idx..].trim_start()),
+    None => (block, ""),
+  };
+
+  match tag {
+    "param" => {
+      let (name, doc) = match rest.find(char::is_whitespace) {
+        Some(idx) => (rest[..idx].to_string(), Some(rest[idx..].trim_start().to_string())),
+        None => (rest.to_string(), None),
+      };
+      JsDocTag::Param {
+        name,
+        doc: doc.filter(|d| !d.is_empty()),
+      }
+    }
+    "returns" | "return" => JsDocTag::Return {
+      doc: Some(rest.to_string()).filter(|d| !d.is_empty()),
+    },
+    "example" => JsDocTag::Example {
+      doc: rest.to_string(),
+    },
+    "deprecated" => JsDocTag::Deprecated {
+      doc: Some(rest.to_string()).filter(|d| !d.is_empty()),
+    },
+    "see" => JsDocTag::See {
+      doc: rest.to_string(),
+    },
+    "template" => JsDocTag::Template {
+      name: rest.to_string(),
+    },
+    _ => JsDocTag::Unsupported {
+      value: block.to_string(),
+    },
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn split_links_resolves_a_simple_link() {
+    assert_eq!(
+      split_links("See {@link Foo} for details."),
+      vec![
+        DocSegment::Text("See "),
+        DocSegment::Link("Foo"),
+        DocSegment::Text(" for details."),
+      ]
+    );
+  }
+
+  #[test]
+  fn split_links_resolves_the_linkcode_variant() {
+    assert_eq!(
+      split_links("{@linkcode Bar}"),
+      vec![DocSegment::Link("Bar")]
+    );
+  }
+
+  #[test]
+  fn split_links_skips_a_malformed_link_without_losing_later_ones() {
+    // A bare `{@link}` used to make `find_link` scan past the next `}`,
+    // swallowing the real `{@link Bar}` that follows into garbage text.
+    assert_eq!(
+      split_links("See {@link} and then {@link Bar} too."),
+      vec![
+        DocSegment::Text("See {@link} and then "),
+        DocSegment::Link("Bar"),
+        DocSegment::Text(" too."),
+      ]
+    );
+  }
+
+  #[test]
+  fn split_links_with_no_links_returns_a_single_text_segment() {
+    assert_eq!(
+      split_links("nothing to link here"),
+      vec![DocSegment::Text("nothing to link here")]
+    );
+  }
+
+  #[test]
+  fn parse_splits_body_from_tags() {
+    let doc = JsDoc::parse("Does a thing.\n@param x the input\n@returns the output");
+    assert_eq!(doc.doc.as_deref(), Some("Does a thing."));
+    assert_eq!(
+      doc.tags,
+      vec![
+        JsDocTag::Param {
+          name: "x".to_string(),
+          doc: Some("the input".to_string()),
+        },
+        JsDocTag::Return {
+          doc: Some("the output".to_string()),
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn parse_with_no_body_yields_none() {
+    let doc = JsDoc::parse("@deprecated");
+    assert_eq!(doc.doc, None);
+    assert_eq!(doc.tags, vec![JsDocTag::Deprecated { doc: None }]);
+  }
+}
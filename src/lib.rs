@@ -0,0 +1,21 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+
+pub mod colors;
+pub mod display;
+pub mod doc_formatter;
+pub mod html;
+pub mod js_doc;
+pub mod markdown;
+pub mod node;
+pub mod printer;
+pub mod search_index;
+
+pub use doc_formatter::DocFormatter;
+pub use html::HtmlRenderer;
+pub use js_doc::JsDoc;
+pub use markdown::MarkdownPrinter;
+pub use node::DocNode;
+pub use node::DocNodeKind;
+pub use printer::Detail;
+pub use printer::DocPrinter;
+pub use search_index::SearchIndex;
@@ -0,0 +1,432 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+
+//! A GitHub-flavored Markdown backend, sibling to the terminal `DocPrinter`.
+//! Implements the same `DocFormatter` trait so the two single-document
+//! backends render signatures, jsdoc, and members consistently as the
+//! `DocNode` model evolves (`HtmlRenderer` sits outside this trait — see
+//! `doc_formatter` for why). Produces a single `docs.md` suitable for
+//! committing straight into a repo.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+use crate::display::SliceDisplayer;
+use crate::doc_formatter::DocFormatter;
+use crate::js_doc::split_links;
+use crate::js_doc::DocSegment;
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::node::kind_order;
+use crate::node::ClassDef;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+use crate::node::EnumDef;
+use crate::node::InterfaceDef;
+use crate::node::NamespaceDef;
+use crate::node::ParamDef;
+
+/// Joins a parent's dot-separated qualified path with a child name, same
+/// convention as `html::qualify`.
+fn qualify(parent_path: &str, name: &str) -> String {
+  if parent_path.is_empty() {
+    name.to_string()
+  } else {
+    format!("{}.{}", parent_path, name)
+  }
+}
+
+/// GitHub strips everything but alphanumerics, `-`, and `_` when slugifying
+/// a heading into an anchor, then lowercases it. Headings are written as
+/// the qualified path (e.g. `A.shared`), so the anchor must be derived the
+/// same way, or duplicate bare names in different namespaces (`A.shared`,
+/// `B.shared`) would collide on GitHub's auto-disambiguated `#shared-1`.
+fn anchor(qualified_path: &str) -> String {
+  qualified_path
+    .chars()
+    .filter(|c| c.is_alphanumeric() || *c == '-' || *c == '_')
+    .collect::<String>()
+    .to_lowercase()
+}
+
+pub struct MarkdownPrinter<'a> {
+  doc_nodes: &'a [DocNode],
+  private: bool,
+  /// Bare name to anchor, for resolving `{@link Symbol}` references. Unlike
+  /// `html::NameCache`, which keeps every declaration and resolves by scope,
+  /// this holds one anchor per bare name: for a name reused across
+  /// namespaces, the first occurrence wins.
+  anchors: HashMap<String, String>,
+}
+
+impl<'a> MarkdownPrinter<'a> {
+  pub fn new(doc_nodes: &'a [DocNode], private: bool) -> Self {
+    let mut anchors = HashMap::new();
+    build_anchor_cache(doc_nodes, "", &mut anchors);
+    MarkdownPrinter {
+      doc_nodes,
+      private,
+      anchors,
+    }
+  }
+
+  pub fn render(&self) -> String {
+    let mut out = String::new();
+    self.render_nodes(self.doc_nodes, "", &mut out);
+    out
+  }
+
+  fn sorted<'b>(&self, doc_nodes: &'b [DocNode]) -> Vec<&'b DocNode> {
+    let mut sorted = doc_nodes.iter().collect::<Vec<_>>();
+    sorted.sort_unstable_by(|a, b| {
+      let kind_cmp = kind_order(&a.kind).cmp(&kind_order(&b.kind));
+      if kind_cmp == core::cmp::Ordering::Equal {
+        a.name.cmp(&b.name)
+      } else {
+        kind_cmp
+      }
+    });
+    sorted
+  }
+
+  /// `parent_path` is the dot-joined, breadcrumb-qualified path of the
+  /// enclosing namespace (empty at the root), so that headings for two
+  /// same-named members of different namespaces anchor to distinct places.
+  fn render_nodes(&self, doc_nodes: &[DocNode], parent_path: &str, out: &mut String) {
+    for node in self.sorted(doc_nodes) {
+      if node.kind == DocNodeKind::Import {
+        continue;
+      }
+      let path = qualify(parent_path, &node.name);
+
+      let _ = writeln!(out, "## {}\n", path);
+      let _ = writeln!(
+        out,
+        "```ts\n{}\n```\n",
+        self.format_signature(node)
+      );
+      out.push_str(&self.format_jsdoc(
+        &node.js_doc,
+        node.function_def.as_ref().map(|f| f.params.as_slice()),
+        node
+          .function_def
+          .as_ref()
+          .map(|f| f.return_type.is_some())
+          .unwrap_or(false),
+      ));
+      out.push('\n');
+
+      if let Some(namespace_def) = &node.namespace_def {
+        self.format_namespace_members_at(namespace_def, &path, out);
+        self.render_nodes(&namespace_def.elements, &path, out);
+      } else {
+        out.push_str(&self.format_members(node));
+      }
+    }
+  }
+
+  fn render_line(&self, line: &str) -> String {
+    split_links(line)
+      .into_iter()
+      .map(|segment| match segment {
+        DocSegment::Text(text) => text.to_string(),
+        DocSegment::Link(name) => match self.anchors.get(name) {
+          Some(anchor) => format!("[{}](#{})", name, anchor),
+          None => name.to_string(),
+        },
+      })
+      .collect()
+  }
+
+  /// A markdown table of parameters, cross-referencing `@param` docs by
+  /// name.
+  fn param_table(&self, params: &[ParamDef], js_doc: &JsDoc) -> String {
+    if params.is_empty() {
+      return String::new();
+    }
+    let mut out = String::from("| Parameter | Type | Description |\n| --- | --- | --- |\n");
+    for param in params {
+      let doc = js_doc.tags.iter().find_map(|tag| match tag {
+        JsDocTag::Param { name, doc } if name == &param.name => {
+          Some(doc.clone().unwrap_or_default())
+        }
+        _ => None,
+      });
+      let ty = param
+        .ts_type
+        .as_ref()
+        .map(|t| t.to_string())
+        .unwrap_or_default();
+      let _ = writeln!(
+        out,
+        "| `{}` | `{}` | {} |",
+        param.name,
+        ty,
+        doc.unwrap_or_default()
+      );
+    }
+    out.push('\n');
+    out
+  }
+}
+
+impl<'a> DocFormatter for MarkdownPrinter<'a> {
+  fn format_signature(&self, node: &DocNode) -> String {
+    match node.kind {
+      DocNodeKind::Class => {
+        let class_def = node.class_def.as_ref().unwrap();
+        let mut s = format!(
+          "{}class {}",
+          if class_def.is_abstract { "abstract " } else { "" },
+          node.name
+        );
+        if !class_def.type_params.is_empty() {
+          let _ = write!(
+            s,
+            "<{}>",
+            SliceDisplayer::new(&class_def.type_params, ", ", false)
+          );
+        }
+        if let Some(extends) = &class_def.extends {
+          let _ = write!(s, " extends {}", extends);
+        }
+        if !class_def.implements.is_empty() {
+          let _ = write!(
+            s,
+            " implements {}",
+            SliceDisplayer::new(&class_def.implements, ", ", false)
+          );
+        }
+        s
+      }
+      DocNodeKind::Enum => format!("enum {}", node.name),
+      DocNodeKind::Interface => {
+        let interface_def = node.interface_def.as_ref().unwrap();
+        let mut s = format!("interface {}", node.name);
+        if !interface_def.extends.is_empty() {
+          let _ = write!(
+            s,
+            " extends {}",
+            SliceDisplayer::new(&interface_def.extends, ", ", false)
+          );
+        }
+        s
+      }
+      DocNodeKind::Namespace => format!("namespace {}", node.name),
+      DocNodeKind::Function => {
+        let function_def = node.function_def.as_ref().unwrap();
+        let mut s = format!(
+          "{}function{} {}",
+          if function_def.is_async { "async " } else { "" },
+          if function_def.is_generator { "*" } else { "" },
+          node.name
+        );
+        if !function_def.type_params.is_empty() {
+          let _ = write!(
+            s,
+            "<{}>",
+            SliceDisplayer::new(&function_def.type_params, ", ", false)
+          );
+        }
+        let _ = write!(
+          s,
+          "({})",
+          SliceDisplayer::new(&function_def.params, ", ", false)
+        );
+        if let Some(return_type) = &function_def.return_type {
+          let _ = write!(s, ": {}", return_type);
+        }
+        s
+      }
+      DocNodeKind::TypeAlias => {
+        let type_alias_def = node.type_alias_def.as_ref().unwrap();
+        format!("type {} = {}", node.name, type_alias_def.ts_type)
+      }
+      DocNodeKind::Variable => {
+        let variable_def = node.variable_def.as_ref().unwrap();
+        let mut s = format!(
+          "{} {}",
+          match variable_def.kind {
+            deno_ast::swc::ast::VarDeclKind::Const => "const",
+            deno_ast::swc::ast::VarDeclKind::Let => "let",
+            deno_ast::swc::ast::VarDeclKind::Var => "var",
+          },
+          node.name
+        );
+        if let Some(ts_type) = &variable_def.ts_type {
+          let _ = write!(s, ": {}", ts_type);
+        }
+        s
+      }
+      DocNodeKind::ModuleDoc | DocNodeKind::Import => String::new(),
+    }
+  }
+
+  fn format_jsdoc(
+    &self,
+    js_doc: &JsDoc,
+    params: Option<&[ParamDef]>,
+    has_return_type: bool,
+  ) -> String {
+    let mut out = String::new();
+    if let Some(doc) = &js_doc.doc {
+      for line in doc.lines() {
+        let _ = writeln!(out, "{}", self.render_line(line));
+      }
+      out.push('\n');
+    }
+
+    for tag in &js_doc.tags {
+      match tag {
+        JsDocTag::Deprecated { doc } => {
+          let _ = write!(out, "> **Deprecated**");
+          if let Some(doc) = doc {
+            let _ = write!(out, ": {}", self.render_line(doc));
+          }
+          out.push_str("\n\n");
+        }
+        JsDocTag::Example { doc } => {
+          let _ = writeln!(out, "**Example:**\n\n```ts\n{}\n```\n", doc);
+        }
+        JsDocTag::See { doc } => {
+          let _ = writeln!(out, "**See:** {}\n", self.render_line(doc));
+        }
+        JsDocTag::Param { .. } => {
+          // rendered below, associated with the signature's param table
+        }
+        JsDocTag::Return { doc } => {
+          if has_return_type {
+            let _ = write!(out, "**Returns:**");
+            if let Some(doc) = doc {
+              let _ = write!(out, " {}", self.render_line(doc));
+            }
+            out.push_str("\n\n");
+          }
+        }
+        JsDocTag::Template { .. } | JsDocTag::Unsupported { .. } => {}
+      }
+    }
+
+    if let Some(params) = params {
+      out.push_str(&self.param_table(params, js_doc));
+    }
+
+    out
+  }
+
+  /// Renders a class/enum/interface's members. For a namespace, the
+  /// `DocFormatter` trait has no room for the qualified path its member
+  /// links need (see `render_nodes`/`format_namespace_members_at`, which
+  /// `render()` actually uses), so this falls back to bare-name anchors —
+  /// fine for callers that just want a node's own signature + members in
+  /// isolation, not for `render()`'s full qualified document.
+  fn format_members(&self, node: &DocNode) -> String {
+    let mut out = String::new();
+    match node.kind {
+      DocNodeKind::Class => self.format_class_members(node.class_def.as_ref().unwrap(), &mut out),
+      DocNodeKind::Enum => self.format_enum_members(node.enum_def.as_ref().unwrap(), &mut out),
+      DocNodeKind::Interface => {
+        self.format_interface_members(node.interface_def.as_ref().unwrap(), &mut out)
+      }
+      DocNodeKind::Namespace => {
+        self.format_namespace_members_at(node.namespace_def.as_ref().unwrap(), "", &mut out)
+      }
+      _ => {}
+    }
+    out
+  }
+}
+
+impl<'a> MarkdownPrinter<'a> {
+  fn format_class_members(&self, class_def: &ClassDef, out: &mut String) {
+    for ctor in &class_def.constructors {
+      let _ = writeln!(out, "### constructor({})\n", SliceDisplayer::new(&ctor.params, ", ", false));
+      out.push_str(&self.param_table(&ctor.params, &ctor.js_doc));
+    }
+    for property in class_def.properties.iter().filter(|p| {
+      self.private
+        || p.accessibility.unwrap_or(deno_ast::swc::ast::Accessibility::Public)
+          != deno_ast::swc::ast::Accessibility::Private
+    }) {
+      let _ = writeln!(out, "### {}\n", property);
+      if let Some(doc) = &property.js_doc.doc {
+        let _ = writeln!(out, "{}\n", self.render_line(doc));
+      }
+    }
+    for method in class_def.methods.iter().filter(|m| {
+      self.private
+        || m.accessibility.unwrap_or(deno_ast::swc::ast::Accessibility::Public)
+          != deno_ast::swc::ast::Accessibility::Private
+    }) {
+      let _ = writeln!(out, "### {}\n", method);
+      out.push_str(&self.format_jsdoc(
+        &method.js_doc,
+        Some(&method.function_def.params),
+        method.function_def.return_type.is_some(),
+      ));
+    }
+  }
+
+  fn format_enum_members(&self, enum_def: &EnumDef, out: &mut String) {
+    for member in &enum_def.members {
+      let _ = writeln!(out, "### {}\n", member.name);
+      if let Some(doc) = &member.js_doc.doc {
+        let _ = writeln!(out, "{}\n", self.render_line(doc));
+      }
+    }
+  }
+
+  fn format_interface_members(&self, interface_def: &InterfaceDef, out: &mut String) {
+    for property in &interface_def.properties {
+      let _ = writeln!(out, "### {}\n", property);
+      if let Some(doc) = &property.js_doc.doc {
+        let _ = writeln!(out, "{}\n", self.render_line(doc));
+      }
+    }
+    for method in &interface_def.methods {
+      let _ = writeln!(out, "### {}\n", method);
+      out.push_str(&self.format_jsdoc(
+        &method.js_doc,
+        Some(&method.params),
+        method.return_type.is_some(),
+      ));
+    }
+  }
+
+  /// `own_path` is the qualified path of the namespace itself, so member
+  /// links point at the same qualified-path anchors `render_nodes` gives
+  /// their headings.
+  fn format_namespace_members_at(
+    &self,
+    namespace_def: &NamespaceDef,
+    own_path: &str,
+    out: &mut String,
+  ) {
+    for element in self.sorted(&namespace_def.elements) {
+      let element_path = qualify(own_path, &element.name);
+      let _ = writeln!(
+        out,
+        "- [{}](#{})",
+        element_path,
+        anchor(&element_path)
+      );
+    }
+    out.push('\n');
+  }
+}
+
+/// `parent_path` is the dot-joined, breadcrumb-qualified path of the
+/// enclosing namespace (empty at the root); see `MarkdownPrinter::anchors`.
+fn build_anchor_cache(doc_nodes: &[DocNode], parent_path: &str, cache: &mut HashMap<String, String>) {
+  for node in doc_nodes {
+    if node.kind == DocNodeKind::Import {
+      continue;
+    }
+    let path = qualify(parent_path, &node.name);
+    cache
+      .entry(node.name.clone())
+      .or_insert_with(|| anchor(&path));
+    if let Some(namespace_def) = &node.namespace_def {
+      build_anchor_cache(&namespace_def.elements, &path, cache);
+    }
+  }
+}
@@ -0,0 +1,282 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::js_doc::JsDoc;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Location {
+  pub filename: String,
+  pub line: usize,
+  pub col: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum DocNodeKind {
+  ModuleDoc,
+  Function,
+  Variable,
+  Class,
+  Enum,
+  Interface,
+  TypeAlias,
+  Namespace,
+  Import,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DocNode {
+  pub kind: DocNodeKind,
+  pub name: String,
+  pub location: Location,
+  pub js_doc: JsDoc,
+
+  pub function_def: Option<FunctionDef>,
+  pub variable_def: Option<VariableDef>,
+  pub enum_def: Option<EnumDef>,
+  pub class_def: Option<ClassDef>,
+  pub type_alias_def: Option<TypeAliasDef>,
+  pub namespace_def: Option<NamespaceDef>,
+  pub interface_def: Option<InterfaceDef>,
+
+  pub accessibility: Option<deno_ast::swc::ast::Accessibility>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ParamDef {
+  pub name: String,
+  pub ts_type: Option<TsTypeDef>,
+}
+
+impl std::fmt::Display for ParamDef {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.name)?;
+    if let Some(ts_type) = &self.ts_type {
+      write!(f, ": {}", ts_type)?;
+    }
+    Ok(())
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TsTypeDef {
+  pub repr: String,
+}
+
+impl std::fmt::Display for TsTypeDef {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.repr)
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DecoratorDef {
+  pub name: String,
+}
+
+impl std::fmt::Display for DecoratorDef {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "@{}", self.name)
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FunctionDef {
+  pub params: Vec<ParamDef>,
+  pub return_type: Option<TsTypeDef>,
+  pub is_async: bool,
+  pub is_generator: bool,
+  pub type_params: Vec<TsTypeParamDef>,
+  pub decorators: Vec<DecoratorDef>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TsTypeParamDef {
+  pub name: String,
+}
+
+impl std::fmt::Display for TsTypeParamDef {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.name)
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct VariableDef {
+  pub ts_type: Option<TsTypeDef>,
+  pub kind: deno_ast::swc::ast::VarDeclKind,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EnumMemberDef {
+  pub name: String,
+  pub js_doc: JsDoc,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EnumDef {
+  pub members: Vec<EnumMemberDef>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ClassConstructorDef {
+  pub name: String,
+  pub js_doc: JsDoc,
+  pub params: Vec<ParamDef>,
+}
+
+impl std::fmt::Display for ClassConstructorDef {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "constructor({})",
+      crate::display::SliceDisplayer::new(&self.params, ", ", false)
+    )
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ClassPropertyDef {
+  pub name: String,
+  pub js_doc: JsDoc,
+  pub ts_type: Option<TsTypeDef>,
+  pub accessibility: Option<deno_ast::swc::ast::Accessibility>,
+  pub decorators: Vec<DecoratorDef>,
+}
+
+impl std::fmt::Display for ClassPropertyDef {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.name)?;
+    if let Some(ts_type) = &self.ts_type {
+      write!(f, ": {}", ts_type)?;
+    }
+    Ok(())
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ClassMethodDef {
+  pub name: String,
+  pub js_doc: JsDoc,
+  pub accessibility: Option<deno_ast::swc::ast::Accessibility>,
+  pub function_def: FunctionDef,
+}
+
+impl std::fmt::Display for ClassMethodDef {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}({})",
+      self.name,
+      crate::display::SliceDisplayer::new(&self.function_def.params, ", ", false)
+    )?;
+    if let Some(return_type) = &self.function_def.return_type {
+      write!(f, ": {}", return_type)?;
+    }
+    Ok(())
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IndexSignatureDef {
+  pub repr: String,
+}
+
+impl std::fmt::Display for IndexSignatureDef {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.repr)
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ClassDef {
+  pub is_abstract: bool,
+  pub extends: Option<String>,
+  pub implements: Vec<String>,
+  pub type_params: Vec<TsTypeParamDef>,
+  pub super_type_params: Vec<TsTypeParamDef>,
+  pub decorators: Vec<DecoratorDef>,
+  pub constructors: Vec<ClassConstructorDef>,
+  pub properties: Vec<ClassPropertyDef>,
+  pub methods: Vec<ClassMethodDef>,
+  pub index_signatures: Vec<IndexSignatureDef>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InterfacePropertyDef {
+  pub name: String,
+  pub js_doc: JsDoc,
+  pub ts_type: Option<TsTypeDef>,
+}
+
+impl std::fmt::Display for InterfacePropertyDef {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.name)?;
+    if let Some(ts_type) = &self.ts_type {
+      write!(f, ": {}", ts_type)?;
+    }
+    Ok(())
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InterfaceMethodDef {
+  pub name: String,
+  pub js_doc: JsDoc,
+  pub params: Vec<ParamDef>,
+  pub return_type: Option<TsTypeDef>,
+}
+
+impl std::fmt::Display for InterfaceMethodDef {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}({})",
+      self.name,
+      crate::display::SliceDisplayer::new(&self.params, ", ", false)
+    )?;
+    if let Some(return_type) = &self.return_type {
+      write!(f, ": {}", return_type)?;
+    }
+    Ok(())
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InterfaceDef {
+  pub extends: Vec<String>,
+  pub type_params: Vec<TsTypeParamDef>,
+  pub properties: Vec<InterfacePropertyDef>,
+  pub methods: Vec<InterfaceMethodDef>,
+  pub index_signatures: Vec<IndexSignatureDef>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TypeAliasDef {
+  pub type_params: Vec<TsTypeParamDef>,
+  pub ts_type: TsTypeDef,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NamespaceDef {
+  pub elements: Vec<DocNode>,
+}
+
+/// The relative ordering backends sort top-level (and namespace-member)
+/// nodes by. Shared so the terminal, HTML, and markdown backends list items
+/// in the same order.
+pub fn kind_order(kind: &DocNodeKind) -> i64 {
+  match kind {
+    DocNodeKind::ModuleDoc => 0,
+    DocNodeKind::Function => 1,
+    DocNodeKind::Variable => 2,
+    DocNodeKind::Class => 3,
+    DocNodeKind::Enum => 4,
+    DocNodeKind::Interface => 5,
+    DocNodeKind::TypeAlias => 6,
+    DocNodeKind::Namespace => 7,
+    DocNodeKind::Import => 8,
+  }
+}
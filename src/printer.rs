@@ -1,11 +1,5 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 
-// TODO(ry) This module builds up output by appending to a string. Instead it
-// should either use a formatting trait
-// https://doc.rust-lang.org/std/fmt/index.html#formatting-traits
-// Or perhaps implement a Serializer for serde
-// https://docs.serde.rs/serde/ser/trait.Serializer.html
-
 // TODO(ry) The methods in this module take ownership of the DocNodes, this is
 // unnecessary and can result in unnecessary copying. Instead they should take
 // references.
@@ -16,14 +10,33 @@ use crate::colors;
 use crate::display::{
   display_abstract, display_async, display_generator, Indent, SliceDisplayer,
 };
+use crate::doc_formatter::DocFormatter;
+use crate::js_doc::split_links;
+use crate::js_doc::DocSegment;
 use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
 use crate::node::DocNode;
 use crate::node::DocNodeKind;
+use crate::node::ParamDef;
+
+/// Controls how much `format_*` emits. `Summary` is meant for browsing large
+/// APIs: only signatures and the first paragraph of `js_doc` are printed for
+/// top-level nodes, member bodies are suppressed, and long `type_alias_def`
+/// right-hand sides are elided. `Full` is today's unabridged output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Detail {
+  Summary,
+  Full,
+}
+
+/// Right-hand sides longer than this are elided in `Detail::Summary` mode.
+const SUMMARY_TYPE_ALIAS_MAX_LEN: usize = 60;
 
 pub struct DocPrinter<'a> {
   doc_nodes: &'a [DocNode],
   use_color: bool,
   private: bool,
+  detail: Detail,
 }
 
 impl<'a> DocPrinter<'a> {
@@ -31,11 +44,13 @@ impl<'a> DocPrinter<'a> {
     doc_nodes: &[DocNode],
     use_color: bool,
     private: bool,
-  ) -> DocPrinter {
+    detail: Detail,
+  ) -> DocPrinter<'_> {
     DocPrinter {
       doc_nodes,
       use_color,
       private,
+      detail,
     }
   }
 
@@ -75,15 +90,27 @@ impl<'a> DocPrinter<'a> {
 
       self.format_signature(w, node, indent)?;
 
-      self.format_jsdoc(w, &node.js_doc, indent + 1)?;
+      self.format_jsdoc(
+        w,
+        &node.js_doc,
+        node.function_def.as_ref().map(|f| f.params.as_slice()),
+        node
+          .function_def
+          .as_ref()
+          .map(|f| f.return_type.is_some())
+          .unwrap_or(false),
+        indent + 1,
+      )?;
       writeln!(w)?;
 
-      match node.kind {
-        DocNodeKind::Class => self.format_class(w, node)?,
-        DocNodeKind::Enum => self.format_enum(w, node)?,
-        DocNodeKind::Interface => self.format_interface(w, node)?,
-        DocNodeKind::Namespace => self.format_namespace(w, node)?,
-        _ => {}
+      if self.detail == Detail::Full {
+        match node.kind {
+          DocNodeKind::Class => self.format_class(w, node)?,
+          DocNodeKind::Enum => self.format_enum(w, node)?,
+          DocNodeKind::Interface => self.format_interface(w, node)?,
+          DocNodeKind::Namespace => self.format_namespace(w, node)?,
+          _ => {}
+        }
       }
     }
 
@@ -95,17 +122,7 @@ impl<'a> DocPrinter<'a> {
   }
 
   fn kind_order(&self, kind: &DocNodeKind) -> i64 {
-    match kind {
-      DocNodeKind::ModuleDoc => 0,
-      DocNodeKind::Function => 1,
-      DocNodeKind::Variable => 2,
-      DocNodeKind::Class => 3,
-      DocNodeKind::Enum => 4,
-      DocNodeKind::Interface => 5,
-      DocNodeKind::TypeAlias => 6,
-      DocNodeKind::Namespace => 7,
-      DocNodeKind::Import => 8,
-    }
+    crate::node::kind_order(kind)
   }
 
   fn format_signature(
@@ -133,26 +150,106 @@ impl<'a> DocPrinter<'a> {
     }
   }
 
+  /// `params`/`has_return_type` let `@param`/`@returns` tags be matched up
+  /// against the signature they document; pass `None`/`false` for doc
+  /// comments that don't sit on a callable (enum members, properties, ...).
   fn format_jsdoc(
     &self,
     w: &mut Formatter<'_>,
     js_doc: &JsDoc,
+    params: Option<&[ParamDef]>,
+    has_return_type: bool,
     indent: i64,
   ) -> FmtResult {
-    // TODO(@kitsonk) this is just a temporary hack
     if let Some(doc) = &js_doc.doc {
-      for line in doc.lines() {
-        writeln!(w, "{}{}", Indent(indent), colors::gray(line))?;
+      let lines = match self.detail {
+        Detail::Full => doc.lines().collect::<Vec<_>>(),
+        Detail::Summary => doc.lines().take_while(|line| !line.trim().is_empty()).collect(),
+      };
+      for line in lines {
+        writeln!(w, "{}{}", Indent(indent), colors::gray(&self.render_line(line)))?;
+      }
+    }
+
+    if self.detail == Detail::Summary {
+      return Ok(());
+    }
+
+    for tag in &js_doc.tags {
+      match tag {
+        JsDocTag::Deprecated { doc } => {
+          write!(w, "{}{}", Indent(indent), colors::red_bold("Deprecated"))?;
+          if let Some(doc) = doc {
+            write!(w, ": {}", colors::gray(doc))?;
+          }
+          writeln!(w)?;
+        }
+        JsDocTag::Example { doc } => {
+          writeln!(w, "{}{}", Indent(indent), colors::bold("Example:"))?;
+          for line in doc.lines() {
+            writeln!(w, "{}{}", Indent(indent + 1), line)?;
+          }
+        }
+        JsDocTag::See { doc } => {
+          writeln!(
+            w,
+            "{}{} {}",
+            Indent(indent),
+            colors::bold("See:"),
+            colors::gray(&self.render_line(doc))
+          )?;
+        }
+        JsDocTag::Param { name, doc } => {
+          let matched = params
+            .map(|params| params.iter().any(|p| &p.name == name))
+            .unwrap_or(false);
+          if matched {
+            write!(w, "{}{} ", Indent(indent), colors::bold(name))?;
+            if let Some(doc) = doc {
+              write!(w, "{}", colors::gray(&self.render_line(doc)))?;
+            }
+            writeln!(w)?;
+          }
+        }
+        JsDocTag::Return { doc } => {
+          if has_return_type {
+            write!(w, "{}{} ", Indent(indent), colors::bold("Returns"))?;
+            if let Some(doc) = doc {
+              write!(w, "{}", colors::gray(&self.render_line(doc)))?;
+            }
+            writeln!(w)?;
+          }
+        }
+        JsDocTag::Template { .. } | JsDocTag::Unsupported { .. } => {}
       }
     }
+
     Ok(())
   }
 
+  /// Resolves `{@link Symbol}` references within a single doc line against
+  /// the top-level node names known to this printer.
+  fn render_line(&self, line: &str) -> String {
+    split_links(line)
+      .into_iter()
+      .map(|segment| match segment {
+        DocSegment::Text(text) => text.to_string(),
+        DocSegment::Link(name) => {
+          if self.doc_nodes.iter().any(|n| n.name == name) {
+            colors::bold(name)
+          } else {
+            name.to_string()
+          }
+        }
+      })
+      .collect()
+  }
+
   fn format_class(&self, w: &mut Formatter<'_>, node: &DocNode) -> FmtResult {
     let class_def = node.class_def.as_ref().unwrap();
     for node in &class_def.constructors {
       writeln!(w, "{}{}", Indent(1), node,)?;
-      self.format_jsdoc(w, &node.js_doc, 2)?;
+      self.format_jsdoc(w, &node.js_doc, Some(&node.params), false, 2)?;
     }
     for node in class_def.properties.iter().filter(|node| {
       self.private
@@ -165,7 +262,7 @@ impl<'a> DocPrinter<'a> {
         writeln!(w, "{}{}", Indent(1), d)?;
       }
       writeln!(w, "{}{}", Indent(1), node,)?;
-      self.format_jsdoc(w, &node.js_doc, 2)?;
+      self.format_jsdoc(w, &node.js_doc, None, false, 2)?;
     }
     for index_sign_def in &class_def.index_signatures {
       writeln!(w, "{}{}", Indent(1), index_sign_def)?;
@@ -181,7 +278,13 @@ impl<'a> DocPrinter<'a> {
         writeln!(w, "{}{}", Indent(1), d)?;
       }
       writeln!(w, "{}{}", Indent(1), node,)?;
-      self.format_jsdoc(w, &node.js_doc, 2)?;
+      self.format_jsdoc(
+        w,
+        &node.js_doc,
+        Some(&node.function_def.params),
+        node.function_def.return_type.is_some(),
+        2,
+      )?;
     }
     writeln!(w)
   }
@@ -190,7 +293,7 @@ impl<'a> DocPrinter<'a> {
     let enum_def = node.enum_def.as_ref().unwrap();
     for member in &enum_def.members {
       writeln!(w, "{}{}", Indent(1), colors::bold(&member.name))?;
-      self.format_jsdoc(w, &member.js_doc, 2)?;
+      self.format_jsdoc(w, &member.js_doc, None, false, 2)?;
     }
     writeln!(w)
   }
@@ -204,11 +307,17 @@ impl<'a> DocPrinter<'a> {
 
     for property_def in &interface_def.properties {
       writeln!(w, "{}{}", Indent(1), property_def)?;
-      self.format_jsdoc(w, &property_def.js_doc, 2)?;
+      self.format_jsdoc(w, &property_def.js_doc, None, false, 2)?;
     }
     for method_def in &interface_def.methods {
       writeln!(w, "{}{}", Indent(1), method_def)?;
-      self.format_jsdoc(w, &method_def.js_doc, 2)?;
+      self.format_jsdoc(
+        w,
+        &method_def.js_doc,
+        Some(&method_def.params),
+        method_def.return_type.is_some(),
+        2,
+      )?;
     }
     for index_sign_def in &interface_def.index_signatures {
       writeln!(w, "{}{}", Indent(1), index_sign_def)?;
@@ -224,7 +333,17 @@ impl<'a> DocPrinter<'a> {
     let elements = &node.namespace_def.as_ref().unwrap().elements;
     for node in elements {
       self.format_signature(w, node, 1)?;
-      self.format_jsdoc(w, &node.js_doc, 2)?;
+      self.format_jsdoc(
+        w,
+        &node.js_doc,
+        node.function_def.as_ref().map(|f| f.params.as_slice()),
+        node
+          .function_def
+          .as_ref()
+          .map(|f| f.return_type.is_some())
+          .unwrap_or(false),
+        2,
+      )?;
     }
     writeln!(w)
   }
@@ -396,7 +515,12 @@ impl<'a> DocPrinter<'a> {
       )?;
     }
 
-    writeln!(w, " = {}", type_alias_def.ts_type)
+    let rhs = type_alias_def.ts_type.to_string();
+    if self.detail == Detail::Summary && rhs.len() > SUMMARY_TYPE_ALIAS_MAX_LEN {
+      writeln!(w, " = {}", colors::italic_gray("{ ... }"))
+    } else {
+      writeln!(w, " = {}", rhs)
+    }
   }
 
   fn format_namespace_signature(
@@ -444,3 +568,44 @@ impl<'a> Display for DocPrinter<'a> {
     self.format(f)
   }
 }
+
+/// `Display` wrapper used to collect the output of a `&mut Formatter`-based
+/// method into a `String`, so it can be exposed through `DocFormatter`.
+struct FmtFn<'a, F>(&'a F)
+where
+  F: Fn(&mut Formatter<'_>) -> FmtResult;
+
+impl<'a, F> Display for FmtFn<'a, F>
+where
+  F: Fn(&mut Formatter<'_>) -> FmtResult,
+{
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    (self.0)(f)
+  }
+}
+
+impl<'a> DocFormatter for DocPrinter<'a> {
+  fn format_signature(&self, node: &DocNode) -> String {
+    FmtFn(&|f| self.format_signature(f, node, 0)).to_string()
+  }
+
+  fn format_jsdoc(
+    &self,
+    js_doc: &JsDoc,
+    params: Option<&[ParamDef]>,
+    has_return_type: bool,
+  ) -> String {
+    FmtFn(&|f| self.format_jsdoc(f, js_doc, params, has_return_type, 0)).to_string()
+  }
+
+  fn format_members(&self, node: &DocNode) -> String {
+    FmtFn(&|f| match node.kind {
+      DocNodeKind::Class => self.format_class(f, node),
+      DocNodeKind::Enum => self.format_enum(f, node),
+      DocNodeKind::Interface => self.format_interface(f, node),
+      DocNodeKind::Namespace => self.format_namespace(f, node),
+      _ => Ok(()),
+    })
+    .to_string()
+  }
+}
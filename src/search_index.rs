@@ -0,0 +1,266 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+
+//! Generates a `search_index.json` describing every documented symbol, so
+//! static front-ends (the HTML backend or a user's own site) can do
+//! client-side fuzzy search without a server.
+
+use serde::Serialize;
+
+use crate::node::kind_order;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+use crate::node::Location;
+
+#[derive(Debug, Serialize)]
+pub struct SearchIndexEntry {
+  pub name: String,
+  pub kind: DocNodeKind,
+  pub path: String,
+  pub location: Location,
+  pub blurb: String,
+}
+
+pub struct SearchIndex<'a> {
+  doc_nodes: &'a [DocNode],
+  private: bool,
+}
+
+impl<'a> SearchIndex<'a> {
+  pub fn new(doc_nodes: &'a [DocNode], private: bool) -> Self {
+    SearchIndex { doc_nodes, private }
+  }
+
+  /// Serializes the full, flattened, deterministically ordered index to a
+  /// JSON string.
+  pub fn to_search_index(&self) -> String {
+    let entries = self.build_entries();
+    serde_json::to_string(&entries).unwrap()
+  }
+
+  fn build_entries(&self) -> Vec<SearchIndexEntry> {
+    let mut entries = Vec::new();
+    Self::collect(self.doc_nodes, None, self.private, &mut entries);
+    entries.sort_unstable_by(|a, b| {
+      let kind_cmp = kind_order(&a.kind).cmp(&kind_order(&b.kind));
+      if kind_cmp == core::cmp::Ordering::Equal {
+        a.path.cmp(&b.path)
+      } else {
+        kind_cmp
+      }
+    });
+    entries
+  }
+
+  fn collect(
+    doc_nodes: &[DocNode],
+    parent_path: Option<&str>,
+    private: bool,
+    entries: &mut Vec<SearchIndexEntry>,
+  ) {
+    for node in doc_nodes {
+      if node.kind == DocNodeKind::Import {
+        continue;
+      }
+      let path = match parent_path {
+        Some(parent) => format!("{}.{}", parent, node.name),
+        None => node.name.clone(),
+      };
+
+      entries.push(SearchIndexEntry {
+        name: node.name.clone(),
+        kind: node.kind,
+        path: path.clone(),
+        location: node.location.clone(),
+        blurb: blurb(&node.js_doc),
+      });
+
+      if let Some(namespace_def) = &node.namespace_def {
+        Self::collect(&namespace_def.elements, Some(&path), private, entries);
+      }
+      if let Some(class_def) = &node.class_def {
+        let prototype_path = format!("{}.prototype", path);
+        for property in class_def.properties.iter().filter(|p| {
+          private
+            || p.accessibility.unwrap_or(deno_ast::swc::ast::Accessibility::Public)
+              != deno_ast::swc::ast::Accessibility::Private
+        }) {
+          entries.push(SearchIndexEntry {
+            name: property.name.clone(),
+            kind: DocNodeKind::Variable,
+            path: format!("{}.{}", prototype_path, property.name),
+            location: node.location.clone(),
+            blurb: blurb(&property.js_doc),
+          });
+        }
+        for method in class_def.methods.iter().filter(|m| {
+          private
+            || m.accessibility.unwrap_or(deno_ast::swc::ast::Accessibility::Public)
+              != deno_ast::swc::ast::Accessibility::Private
+        }) {
+          entries.push(SearchIndexEntry {
+            name: method.name.clone(),
+            kind: DocNodeKind::Function,
+            path: format!("{}.{}", prototype_path, method.name),
+            location: node.location.clone(),
+            blurb: blurb(&method.js_doc),
+          });
+        }
+      }
+      if let Some(interface_def) = &node.interface_def {
+        for property in &interface_def.properties {
+          entries.push(SearchIndexEntry {
+            name: property.name.clone(),
+            kind: DocNodeKind::Variable,
+            path: format!("{}.{}", path, property.name),
+            location: node.location.clone(),
+            blurb: blurb(&property.js_doc),
+          });
+        }
+        for method in &interface_def.methods {
+          entries.push(SearchIndexEntry {
+            name: method.name.clone(),
+            kind: DocNodeKind::Function,
+            path: format!("{}.{}", path, method.name),
+            location: node.location.clone(),
+            blurb: blurb(&method.js_doc),
+          });
+        }
+      }
+    }
+  }
+}
+
+fn blurb(js_doc: &crate::js_doc::JsDoc) -> String {
+  js_doc
+    .doc
+    .as_ref()
+    .and_then(|doc| doc.lines().next())
+    .unwrap_or_default()
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::js_doc::JsDoc;
+  use crate::node::ClassDef;
+  use crate::node::ClassPropertyDef;
+  use crate::node::NamespaceDef;
+
+  fn node(kind: DocNodeKind, name: &str) -> DocNode {
+    DocNode {
+      kind,
+      name: name.to_string(),
+      location: Location {
+        filename: "file.ts".to_string(),
+        line: 1,
+        col: 0,
+      },
+      js_doc: JsDoc::default(),
+      function_def: None,
+      variable_def: None,
+      enum_def: None,
+      class_def: None,
+      type_alias_def: None,
+      namespace_def: None,
+      interface_def: None,
+      accessibility: None,
+    }
+  }
+
+  #[test]
+  fn collect_flattens_namespace_members_with_dotted_paths() {
+    let mut shared = node(DocNodeKind::Function, "shared");
+    shared.js_doc = JsDoc {
+      doc: Some("Does a thing.".to_string()),
+      tags: vec![],
+    };
+    let mut a = node(DocNodeKind::Namespace, "A");
+    a.namespace_def = Some(NamespaceDef {
+      elements: vec![shared],
+    });
+
+    let index = SearchIndex::new(std::slice::from_ref(&a), true);
+    let entries = index.build_entries();
+
+    // Functions sort before namespaces per `kind_order`, so the flattened
+    // member shows up first despite being nested under "A".
+    let paths: Vec<&str> = entries.iter().map(|e| e.path.as_str()).collect();
+    assert_eq!(paths, vec!["A.shared", "A"]);
+    assert_eq!(entries[0].blurb, "Does a thing.");
+  }
+
+  #[test]
+  fn collect_keys_class_members_under_a_prototype_path() {
+    let mut class = node(DocNodeKind::Class, "Foo");
+    class.class_def = Some(ClassDef {
+      is_abstract: false,
+      extends: None,
+      implements: vec![],
+      type_params: vec![],
+      super_type_params: vec![],
+      decorators: vec![],
+      constructors: vec![],
+      properties: vec![ClassPropertyDef {
+        name: "bar".to_string(),
+        js_doc: JsDoc::default(),
+        ts_type: None,
+        accessibility: None,
+        decorators: vec![],
+      }],
+      methods: vec![],
+      index_signatures: vec![],
+    });
+
+    let index = SearchIndex::new(std::slice::from_ref(&class), true);
+    let entries = index.build_entries();
+
+    assert!(entries.iter().any(|e| e.path == "Foo.prototype.bar"));
+  }
+
+  #[test]
+  fn collect_omits_private_class_members_unless_private_is_requested() {
+    let mut class = node(DocNodeKind::Class, "Foo");
+    class.class_def = Some(ClassDef {
+      is_abstract: false,
+      extends: None,
+      implements: vec![],
+      type_params: vec![],
+      super_type_params: vec![],
+      decorators: vec![],
+      constructors: vec![],
+      properties: vec![ClassPropertyDef {
+        name: "secret".to_string(),
+        js_doc: JsDoc::default(),
+        ts_type: None,
+        accessibility: Some(deno_ast::swc::ast::Accessibility::Private),
+        decorators: vec![],
+      }],
+      methods: vec![],
+      index_signatures: vec![],
+    });
+
+    let public_only = SearchIndex::new(std::slice::from_ref(&class), false);
+    assert!(!public_only
+      .build_entries()
+      .iter()
+      .any(|e| e.path == "Foo.prototype.secret"));
+
+    let with_private = SearchIndex::new(std::slice::from_ref(&class), true);
+    assert!(with_private
+      .build_entries()
+      .iter()
+      .any(|e| e.path == "Foo.prototype.secret"));
+  }
+
+  #[test]
+  fn build_entries_sorts_by_kind_then_path() {
+    let nodes = vec![node(DocNodeKind::Variable, "b"), node(DocNodeKind::Function, "a")];
+    let index = SearchIndex::new(&nodes, true);
+    let entries = index.build_entries();
+
+    // Function sorts before Variable per `kind_order`, regardless of name.
+    assert_eq!(entries[0].name, "a");
+    assert_eq!(entries[1].name, "b");
+  }
+}